@@ -0,0 +1,56 @@
+//! Backend and presentation configuration.
+
+/// Vsync / present-mode preference, mapped to a supported `wgpu::PresentMode` at surface setup time.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PresentMode {
+    /// Vsync on; the CPU waits for the display to be ready before presenting. Always supported.
+    Fifo,
+    /// Low-latency vsync that never blocks the CPU. Falls back to `Fifo` if unsupported.
+    Mailbox,
+    /// No vsync; presents as soon as a frame is ready and may tear. Falls back to `Fifo` if unsupported.
+    Immediate
+}
+
+impl PresentMode {
+    fn as_wgpu(self) -> wgpu::PresentMode {
+        match self {
+            PresentMode::Fifo => wgpu::PresentMode::Fifo,
+            PresentMode::Mailbox => wgpu::PresentMode::Mailbox,
+            PresentMode::Immediate => wgpu::PresentMode::Immediate
+        }
+    }
+}
+
+/// Configuration for backend selection and surface presentation, passed into [`crate::run`].
+#[derive(Copy, Clone, Debug)]
+pub struct FrugConfig {
+    /// Which graphics backends (Vulkan, Metal, DX12, ...) the `wgpu::Instance` may pick from.
+    pub backends: wgpu::Backends,
+    /// Whether to prefer a low-power or high-performance adapter.
+    pub power_preference: wgpu::PowerPreference,
+    /// The preferred vsync behavior.
+    pub present_mode: PresentMode
+}
+
+impl Default for FrugConfig {
+    fn default() -> Self {
+        Self {
+            backends: wgpu::Backends::all(),
+            power_preference: wgpu::PowerPreference::default(),
+            present_mode: PresentMode::Fifo
+        }
+    }
+}
+
+impl FrugConfig {
+    /// Picks the requested present mode if the surface supports it, and otherwise falls back
+    /// to `Fifo`, which every surface supports.
+    pub(crate) fn select_present_mode(&self, supported: &[wgpu::PresentMode]) -> wgpu::PresentMode {
+        let wanted = self.present_mode.as_wgpu();
+        if supported.contains(&wanted) {
+            wanted
+        } else {
+            wgpu::PresentMode::Fifo
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Keyboard and mouse input state.
+
+use std::collections::HashSet;
+use winit::dpi::PhysicalPosition;
+use winit::event::{ElementState, KeyboardInput, MouseScrollDelta};
+
+/// A keyboard key, re-exported from `winit` so users don't need to depend on it directly.
+pub type Key = winit::event::VirtualKeyCode;
+
+/// A mouse button, re-exported from `winit` so users don't need to depend on it directly.
+pub type MouseButton = winit::event::MouseButton;
+
+/// Tracks keyboard and mouse state.
+///
+/// Built up by the event loop in [`crate::run`] and handed to the user's loop closure each frame.
+/// The "just pressed"/"just released" sets only hold true for the frame the transition happened in.
+#[derive(Debug, Default)]
+pub struct Input {
+    pressed_keys: HashSet<Key>,
+    just_pressed_keys: HashSet<Key>,
+    just_released_keys: HashSet<Key>,
+    pressed_buttons: HashSet<MouseButton>,
+    just_pressed_buttons: HashSet<MouseButton>,
+    just_released_buttons: HashSet<MouseButton>,
+    mouse_position: (f64, f64),
+    scroll_delta: (f32, f32)
+}
+
+impl Input {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn handle_keyboard_input(&mut self, input: KeyboardInput) {
+        let Some(key) = input.virtual_keycode else { return; };
+        match input.state {
+            ElementState::Pressed => {
+                if self.pressed_keys.insert(key) {
+                    self.just_pressed_keys.insert(key);
+                }
+            }
+            ElementState::Released => {
+                self.pressed_keys.remove(&key);
+                self.just_released_keys.insert(key);
+            }
+        }
+    }
+
+    pub(crate) fn handle_mouse_input(&mut self, state: ElementState, button: MouseButton) {
+        match state {
+            ElementState::Pressed => {
+                if self.pressed_buttons.insert(button) {
+                    self.just_pressed_buttons.insert(button);
+                }
+            }
+            ElementState::Released => {
+                self.pressed_buttons.remove(&button);
+                self.just_released_buttons.insert(button);
+            }
+        }
+    }
+
+    pub(crate) fn handle_cursor_moved(&mut self, position: PhysicalPosition<f64>) {
+        self.mouse_position = (position.x, position.y);
+    }
+
+    pub(crate) fn handle_mouse_wheel(&mut self, delta: MouseScrollDelta) {
+        let (x, y) = match delta {
+            MouseScrollDelta::LineDelta(x, y) => (x, y),
+            MouseScrollDelta::PixelDelta(position) => (position.x as f32, position.y as f32)
+        };
+        self.scroll_delta.0 += x;
+        self.scroll_delta.1 += y;
+    }
+
+    /// Clears the per-frame "just pressed"/"just released" deltas.
+    pub(crate) fn clear_deltas(&mut self) {
+        self.just_pressed_keys.clear();
+        self.just_released_keys.clear();
+        self.just_pressed_buttons.clear();
+        self.just_released_buttons.clear();
+        self.scroll_delta = (0.0, 0.0);
+    }
+
+    /// Returns `true` while `key` is held down.
+    pub fn is_key_pressed(&self, key: Key) -> bool {
+        self.pressed_keys.contains(&key)
+    }
+
+    /// Returns `true` only on the frame `key` was first pressed.
+    pub fn is_key_just_pressed(&self, key: Key) -> bool {
+        self.just_pressed_keys.contains(&key)
+    }
+
+    /// Returns `true` only on the frame `key` was released.
+    pub fn is_key_just_released(&self, key: Key) -> bool {
+        self.just_released_keys.contains(&key)
+    }
+
+    /// Returns `true` while `button` is held down.
+    pub fn is_mouse_button_pressed(&self, button: MouseButton) -> bool {
+        self.pressed_buttons.contains(&button)
+    }
+
+    /// Returns `true` only on the frame `button` was first pressed.
+    pub fn is_mouse_button_just_pressed(&self, button: MouseButton) -> bool {
+        self.just_pressed_buttons.contains(&button)
+    }
+
+    /// Returns `true` only on the frame `button` was released.
+    pub fn is_mouse_button_just_released(&self, button: MouseButton) -> bool {
+        self.just_released_buttons.contains(&button)
+    }
+
+    /// Returns the cursor position in physical pixels, relative to the window's top-left corner.
+    pub fn mouse_position(&self) -> (f64, f64) {
+        self.mouse_position
+    }
+
+    /// Returns the scroll delta accumulated since the last frame.
+    pub fn scroll_delta(&self) -> (f32, f32) {
+        self.scroll_delta
+    }
+}
@@ -2,18 +2,27 @@
 //! 
 //! FRUG aims to include the following features (unchecked items are the ones still under development):
 //! - [x] Window management
-//! - [ ]  Loading & rendering textures
+//! - [x]  Loading & rendering textures
 //! - [ ]  Rotating textures
 //! - [ ]  Scaling textures
-//! - [ ]  Alpha blending for textures
-//! - [ ]  Choosing a specific backend (aka. Direct X, Metal, Vulkan, etc.)
-//! - [ ]  Writing and using custom shaders
+//! - [x]  Alpha blending for textures
+//! - [x]  Choosing a specific backend (aka. Direct X, Metal, Vulkan, etc.)
+//! - [x]  Writing and using custom shaders
 //! - [ ]  Handle window state events
-//! - [ ]  Handle Mouse input
-//! - [ ]  Handle Keyboard input
+//! - [x]  Handle Mouse input
+//! - [x]  Handle Keyboard input
 //! - [ ]  Playing audio
 //! - [ ]  Configure audio
 
+mod config;
+mod input;
+mod post_processing;
+mod text;
+mod texture;
+
+pub use config::{FrugConfig, PresentMode};
+pub use input::{Input, Key, MouseButton};
+pub use texture::TextureHandle;
 
 use wgpu::util::DeviceExt;
 use winit::{
@@ -25,17 +34,28 @@ use winit::{
 /// Vertex struct
 #[repr(C)]
 #[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
-struct Vertex {
+pub struct Vertex {
     position: [f32; 3],
-    color: [f32; 3]
+    color: [f32; 3],
+    tex_coords: [f32; 2]
 }
 
 /// Implementation of Vertex methods
 impl Vertex {
+    /// Creates a new vertex with the given position, color, and texture coordinates.
+    ///
+    /// # Example
+    /// ```
+    /// let v = frug::Vertex::new([0.0, 0.5, 0.0], [1.0, 1.0, 1.0], [0.5, 0.0]);
+    /// ```
+    pub fn new(position: [f32; 3], color: [f32; 3], tex_coords: [f32; 2]) -> Self {
+        Self { position, color, tex_coords }
+    }
+
     fn desc<'a>() -> wgpu::VertexBufferLayout<'a> {
-        wgpu::VertexBufferLayout { 
-            array_stride: std::mem::size_of::<Vertex>() as wgpu::BufferAddress, 
-            step_mode: wgpu::VertexStepMode::Vertex, 
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<Vertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
             attributes: &[
                 wgpu::VertexAttribute {
                     offset: 0,
@@ -46,30 +66,17 @@ impl Vertex {
                     offset: std::mem::size_of::<[f32; 3]>() as wgpu::BufferAddress,
                     shader_location: 1,
                     format: wgpu::VertexFormat::Float32x3
+                },
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 3]>() as wgpu::BufferAddress * 2,
+                    shader_location: 2,
+                    format: wgpu::VertexFormat::Float32x2
                 }
-            ] 
+            ]
         }
     }
 }
 
-
-// - - - - - TEST! - - - - -
-// We should remove this in the future so we can create these in frug usage.
-const VERTICES: &[Vertex] = &[
-    Vertex { position: [-0.0868241, 0.49240386, 0.0], color: [0.5, 0.0, 0.5] },
-    Vertex { position: [-0.49513406, 0.06958647, 0.0], color: [0.5, 0.0, 0.5] },
-    Vertex { position: [-0.21918549, -0.44939706, 0.0], color: [0.5, 0.0, 0.5] },
-    Vertex { position: [0.35966998, -0.3473291, 0.0], color: [0.5, 0.0, 0.5] },
-    Vertex { position: [0.44147372, 0.2347359, 0.0], color: [0.5, 0.0, 0.5] },
-];
-
-const INDICES: &[u16] = &[
-    0, 1, 4,
-    1, 2, 4,
-    2, 3, 4
-];
-// - - - - - TEST! - - - - -
-
 /// The Frug instance.
 /// Contains the surface in which we draw, the device we're using, the queue, the surface configuration, surface size, window, background color, and render pipeline.
 pub struct FrugInstance {
@@ -83,13 +90,21 @@ pub struct FrugInstance {
     render_pipeline: wgpu::RenderPipeline,
     vertex_buffer: wgpu::Buffer,
     index_buffer: wgpu::Buffer,
-    num_indices: u32
+    num_indices: u32,
+    texture_bind_group_layout: wgpu::BindGroupLayout,
+    textures: Vec<texture::Texture>,
+    active_texture: TextureHandle,
+    text_renderer: Option<text::TextRenderer>,
+    effects: Vec<post_processing::Effect>,
+    effect_sampler: wgpu::Sampler,
+    offscreen_textures: [post_processing::OffscreenTexture; 2],
+    start_time: std::time::Instant
 }
 
 /// Implementation of FrugInstance methods
 impl FrugInstance {
     /// Creates a new instance of FrugInstance, instantiating the window, configuration, and the surface to draw in.
-    async fn new_instance(window_title: &str, event_loop: &EventLoop<()>) -> Self {
+    async fn new_instance(window_title: &str, frug_config: &FrugConfig, event_loop: &EventLoop<()>) -> Self {
         // Enable wgpu logging
         env_logger::init();
 
@@ -99,15 +114,18 @@ impl FrugInstance {
         let size = window.inner_size();
         let background_color = wgpu::Color { r: 0.0, g: 0.0, b: 0.0, a: 1.0 };
 
-        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::default());
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+            backends: frug_config.backends,
+            ..Default::default()
+        });
 
-        let surface = unsafe { 
+        let surface = unsafe {
             instance.create_surface(&window)
         }.unwrap();
 
         let adapter = instance.request_adapter(
             &wgpu::RequestAdapterOptions {
-                power_preference: wgpu::PowerPreference::default(),
+                power_preference: frug_config.power_preference,
                 compatible_surface: Some(&surface),
                 force_fallback_adapter: false
             }
@@ -132,7 +150,7 @@ impl FrugInstance {
             format: surface_format,
             width: size.width,
             height: size.height,
-            present_mode: surface_caps.present_modes[0],
+            present_mode: frug_config.select_present_mode(&surface_caps.present_modes),
             alpha_mode: surface_caps.alpha_modes[0],
             view_formats: vec![],
         };
@@ -140,9 +158,11 @@ impl FrugInstance {
 
         let shader = device.create_shader_module(wgpu::include_wgsl!("shader.wgsl"));
 
+        let texture_bind_group_layout = texture::Texture::create_bind_group_layout(&device);
+
         let render_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("Render Pipeline Layout"),
-            bind_group_layouts: &[],
+            bind_group_layouts: &[&texture_bind_group_layout],
             push_constant_ranges: &[]
         });
 
@@ -158,10 +178,10 @@ impl FrugInstance {
             fragment: Some(wgpu::FragmentState { 
                 module: &shader, 
                 entry_point: "fs_main", 
-                targets: &[Some(wgpu::ColorTargetState { 
-                    format: config.format, 
-                    blend: Some(wgpu::BlendState::REPLACE), 
-                    write_mask: wgpu::ColorWrites::ALL 
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: config.format,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL
                 })]
             }),
             primitive: wgpu::PrimitiveState { 
@@ -182,19 +202,38 @@ impl FrugInstance {
             multiview: None
         });
 
+        // No geometry yet; users provide their own via `update_buffers`.
         let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("Vertex Buffer"),
-            contents: bytemuck::cast_slice(VERTICES),
-            usage: wgpu::BufferUsages::VERTEX
+            contents: &[],
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST
         });
 
         let index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("Index Buffer"),
-            contents: bytemuck::cast_slice(INDICES),
-            usage: wgpu::BufferUsages::INDEX
+            contents: &[],
+            usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST
         });
 
-        let num_indices = INDICES.len() as u32;
+        let num_indices = 0;
+
+        let default_texture = texture::Texture::from_color(&device, &queue, &texture_bind_group_layout);
+        let textures = vec![default_texture];
+        let active_texture = TextureHandle(0);
+
+        let effect_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+        let offscreen_textures = [
+            post_processing::OffscreenTexture::new(&device, config.format, config.width, config.height),
+            post_processing::OffscreenTexture::new(&device, config.format, config.width, config.height)
+        ];
 
         Self {
             window,
@@ -207,7 +246,15 @@ impl FrugInstance {
             render_pipeline,
             vertex_buffer,
             index_buffer,
-            num_indices
+            num_indices,
+            texture_bind_group_layout,
+            textures,
+            active_texture,
+            text_renderer: None,
+            effects: Vec::new(),
+            effect_sampler,
+            offscreen_textures,
+            start_time: std::time::Instant::now()
         }
     }
 
@@ -218,6 +265,10 @@ impl FrugInstance {
             self.config.width = new_size.width;
             self.config.height = new_size.height;
             self.surface.configure(&self.device, &self.config);
+            self.offscreen_textures = [
+                post_processing::OffscreenTexture::new(&self.device, self.config.format, self.config.width, self.config.height),
+                post_processing::OffscreenTexture::new(&self.device, self.config.format, self.config.width, self.config.height)
+            ];
         }
     }
 
@@ -225,35 +276,61 @@ impl FrugInstance {
     fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
         let output = self.surface.get_current_texture()?;
 
-        let view = output.texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let surface_view = output.texture.create_view(&wgpu::TextureViewDescriptor::default());
 
         let mut encoder = self.device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
             label: Some("Render Encoder")
         });
 
+        // Render the scene. When there are post-processing effects, it goes to an offscreen
+        // texture that becomes the first effect's input; otherwise it goes straight to the surface.
+        let scene_view = if self.effects.is_empty() { &surface_view } else { &self.offscreen_textures[0].view };
+
         {
-            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor { 
-                label: Some("Render Pass"), 
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Render Pass"),
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &view, 
-                    resolve_target: None, 
-                    ops: wgpu::Operations { 
-                        load: wgpu::LoadOp::Clear(self.background_color), 
+                    view: scene_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(self.background_color),
                         store: true
                     }
-                })], 
+                })],
                 depth_stencil_attachment: None
             });
 
             render_pass.set_pipeline(&self.render_pipeline);
+            render_pass.set_bind_group(0, &self.textures[self.active_texture.0].bind_group, &[]);
             render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
             render_pass.set_index_buffer(self.index_buffer.slice(..), wgpu::IndexFormat::Uint16);
             render_pass.draw_indexed(0..self.num_indices, 0, 0..1);
         }
 
+        if !self.effects.is_empty() {
+            let elapsed_time = self.start_time.elapsed().as_secs_f32();
+            let resolution = (self.config.width, self.config.height);
+            let last_effect = self.effects.len() - 1;
+
+            for (i, effect) in self.effects.iter().enumerate() {
+                let input_view = &self.offscreen_textures[i % 2].view;
+                let output_view = if i == last_effect { &surface_view } else { &self.offscreen_textures[(i + 1) % 2].view };
+                effect.run(&self.device, &self.queue, &self.effect_sampler, &mut encoder, input_view, output_view, resolution, elapsed_time);
+            }
+        }
+
+        if let Some(text_renderer) = &mut self.text_renderer {
+            text_renderer.draw_queued(&self.device, &mut encoder, &surface_view, self.config.width, self.config.height);
+            text_renderer.finish();
+        }
+
         self.queue.submit(std::iter::once(encoder.finish()));
         output.present();
 
+        if let Some(text_renderer) = &mut self.text_renderer {
+            text_renderer.recall();
+        }
+
         Ok(())
     }
 
@@ -269,26 +346,200 @@ impl FrugInstance {
     pub fn set_background_color(&mut self, color: wgpu::Color) {
         self.background_color = color;
     }
+
+    /// Loads an image file from disk and uploads it to the GPU as a texture.
+    ///
+    /// Returns a [`TextureHandle`] which becomes the active texture used for subsequent draws.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let handle = my_frug_instance.load_texture("assets/player.png");
+    /// ```
+    pub fn load_texture(&mut self, path: &str) -> TextureHandle {
+        let tex = texture::Texture::from_path(&self.device, &self.queue, &self.texture_bind_group_layout, path);
+        self.textures.push(tex);
+        let handle = TextureHandle(self.textures.len() - 1);
+        self.active_texture = handle;
+        handle
+    }
+
+    /// Selects which loaded texture is bound for subsequent draws.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let player_texture = my_frug_instance.load_texture("assets/player.png");
+    /// let background_texture = my_frug_instance.load_texture("assets/background.png");
+    /// my_frug_instance.set_active_texture(background_texture);
+    /// ```
+    pub fn set_active_texture(&mut self, handle: TextureHandle) {
+        self.active_texture = handle;
+    }
+
+    /// Replaces the geometry that gets drawn each frame.
+    ///
+    /// Writes into the existing vertex/index buffers when the new data still fits, and only
+    /// reallocates them when it grows past their current capacity.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let vertices = [
+    ///     frug::Vertex::new([0.0, 0.5, 0.0], [1.0, 0.0, 0.0], [0.5, 0.0]),
+    ///     frug::Vertex::new([-0.5, -0.5, 0.0], [0.0, 1.0, 0.0], [0.0, 1.0]),
+    ///     frug::Vertex::new([0.5, -0.5, 0.0], [0.0, 0.0, 1.0], [1.0, 1.0]),
+    /// ];
+    /// let indices = [0, 1, 2];
+    /// my_frug_instance.update_buffers(&vertices, &indices);
+    /// ```
+    pub fn update_buffers(&mut self, vertices: &[Vertex], indices: &[u16]) {
+        let vertex_bytes = bytemuck::cast_slice(vertices);
+        if vertex_bytes.len() as wgpu::BufferAddress <= self.vertex_buffer.size() {
+            self.queue.write_buffer(&self.vertex_buffer, 0, vertex_bytes);
+        } else {
+            self.vertex_buffer = self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Vertex Buffer"),
+                contents: vertex_bytes,
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST
+            });
+        }
+
+        // `write_buffer` needs a multiple of `wgpu::COPY_BUFFER_ALIGNMENT` bytes, so an odd number
+        // of indices is padded with one unused index.
+        let mut index_bytes = bytemuck::cast_slice::<u16, u8>(indices).to_vec();
+        let align = wgpu::COPY_BUFFER_ALIGNMENT as usize;
+        index_bytes.resize((index_bytes.len() + align - 1) / align * align, 0);
+        if index_bytes.len() as wgpu::BufferAddress <= self.index_buffer.size() {
+            self.queue.write_buffer(&self.index_buffer, 0, &index_bytes);
+        } else {
+            self.index_buffer = self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Index Buffer"),
+                contents: &index_bytes,
+                usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST
+            });
+        }
+
+        self.num_indices = indices.len() as u32;
+    }
+
+    /// Loads a TTF font from disk, enabling `draw_text`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// my_frug_instance.load_font("assets/font.ttf");
+    /// ```
+    pub fn load_font(&mut self, font_path: &str) {
+        self.text_renderer = Some(text::TextRenderer::new(&self.device, self.config.format, font_path));
+    }
+
+    /// Queues a line of text to be drawn this frame at `position`, in screen pixels from the top-left corner.
+    ///
+    /// Panics if no font has been loaded with `load_font`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// my_frug_instance.draw_text("Score: 42", (10.0, 10.0), [1.0, 1.0, 1.0, 1.0], 24.0);
+    /// ```
+    pub fn draw_text(&mut self, text: &str, position: (f32, f32), color: [f32; 4], scale: f32) {
+        let text_renderer = self.text_renderer.as_mut().expect("No font loaded. Call `load_font` before `draw_text`.");
+        text_renderer.queue(text, position, color, scale);
+    }
+
+    /// Compiles a WGSL fragment shader and appends it to the post-processing effect chain.
+    ///
+    /// Each effect is a full-screen pass: it samples the previous pass's output through
+    /// `t_input`/`s_input`, and can read the screen resolution and elapsed time in seconds from
+    /// a uniform named `effect` (`effect.resolution`, `effect.time`). Effects run in the order
+    /// they were added, with the last one writing to the screen.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let grayscale = "
+    ///     @fragment
+    ///     fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    ///         let color = textureSample(t_input, s_input, in.uv);
+    ///         let gray = dot(color.rgb, vec3<f32>(0.299, 0.587, 0.114));
+    ///         return vec4<f32>(gray, gray, gray, color.a);
+    ///     }
+    /// ";
+    /// my_frug_instance.add_effect(grayscale);
+    /// ```
+    pub fn add_effect(&mut self, wgsl_source: &str) {
+        let effect = post_processing::Effect::new(&self.device, self.config.format, wgsl_source);
+        self.effects.push(effect);
+    }
+}
+
+/// A safe, per-frame handle into a running [`FrugInstance`].
+///
+/// Exposes the operations that are safe to call from inside the frame loop (background color,
+/// geometry, textures, text, effects, input) without exposing the underlying wgpu device/queue.
+pub struct FrugContext<'a> {
+    instance: &'a mut FrugInstance,
+    input: &'a Input
+}
+
+impl<'a> FrugContext<'a> {
+    /// Sets new background color. See [`FrugInstance::set_background_color`].
+    pub fn set_background_color(&mut self, color: wgpu::Color) {
+        self.instance.set_background_color(color);
+    }
+
+    /// Loads an image file from disk as a texture. See [`FrugInstance::load_texture`].
+    pub fn load_texture(&mut self, path: &str) -> TextureHandle {
+        self.instance.load_texture(path)
+    }
+
+    /// Selects which loaded texture is bound for subsequent draws. See [`FrugInstance::set_active_texture`].
+    pub fn set_active_texture(&mut self, handle: TextureHandle) {
+        self.instance.set_active_texture(handle);
+    }
+
+    /// Replaces the geometry drawn this frame. See [`FrugInstance::update_buffers`].
+    pub fn update_buffers(&mut self, vertices: &[Vertex], indices: &[u16]) {
+        self.instance.update_buffers(vertices, indices);
+    }
+
+    /// Loads a TTF font from disk. See [`FrugInstance::load_font`].
+    pub fn load_font(&mut self, font_path: &str) {
+        self.instance.load_font(font_path);
+    }
+
+    /// Queues a line of text to be drawn this frame. See [`FrugInstance::draw_text`].
+    pub fn draw_text(&mut self, text: &str, position: (f32, f32), color: [f32; 4], scale: f32) {
+        self.instance.draw_text(text, position, color, scale);
+    }
+
+    /// Appends a post-processing effect. See [`FrugInstance::add_effect`].
+    pub fn add_effect(&mut self, wgsl_source: &str) {
+        self.instance.add_effect(wgsl_source);
+    }
+
+    /// Returns the current keyboard and mouse state.
+    pub fn input(&self) -> &Input {
+        self.input
+    }
 }
 
 /// Starts running your project.
-/// 
+///
 /// Should receive a string which will be the title for the window created. It should also receive a loop which will be the main loop for your game/app.
-/// * `window_title (&str)`         - The title for your window.
-/// * `window_loop (static Fn())`   - The loop you want to execute with each frame.
-/// 
+/// * `window_title (&str)`      - The title for your window.
+/// * `frug_config (FrugConfig)` - Backend and presentation configuration. Use `FrugConfig::default()` if you don't need to customize it.
+/// * `window_loop (static FnMut(&mut FrugContext, f32))` - The loop you want to execute with each frame. Receives a [`FrugContext`] and the number of seconds elapsed since the previous frame.
+///
 /// # Example:
-/// 
+///
 /// ```
-/// let my_loop = || {
+/// let my_loop = |ctx: &mut frug::FrugContext, dt: f32| {
 ///     // your code
 /// };
-/// frug::run("My Game", my_loop);
+/// frug::run("My Game", frug::FrugConfig::default(), my_loop);
 /// ```
-pub fn run<F: 'static + Fn()>(window_title: &str, window_loop: F) {
+pub fn run<F: 'static + FnMut(&mut FrugContext, f32)>(window_title: &str, frug_config: FrugConfig, mut window_loop: F) {
     // setup
     let event_loop = EventLoop::new();
-    let mut frug_instance = pollster::block_on( FrugInstance::new_instance(window_title, &event_loop));
+    let mut frug_instance = pollster::block_on( FrugInstance::new_instance(window_title, &frug_config, &event_loop));
+    let mut input = Input::new();
+    let mut last_frame_time = std::time::Instant::now();
 
     // Run the loop
     event_loop.run(move |event, _, control_flow| {
@@ -299,7 +550,7 @@ pub fn run<F: 'static + Fn()>(window_title: &str, window_loop: F) {
             Event::WindowEvent {
                 ref event,
                 window_id,
-            } 
+            }
             // Window events
             if window_id == frug_instance.window.id() => match event {
                 // Close
@@ -314,10 +565,31 @@ pub fn run<F: 'static + Fn()>(window_title: &str, window_loop: F) {
                 WindowEvent::ScaleFactorChanged { new_inner_size, .. } => {
                     frug_instance.resize(**new_inner_size);
                 }
+
+                // Keyboard & mouse input
+                WindowEvent::KeyboardInput { input: key_input, .. } => {
+                    input.handle_keyboard_input(*key_input);
+                },
+                WindowEvent::CursorMoved { position, .. } => {
+                    input.handle_cursor_moved(*position);
+                },
+                WindowEvent::MouseInput { state, button, .. } => {
+                    input.handle_mouse_input(*state, *button);
+                },
+                WindowEvent::MouseWheel { delta, .. } => {
+                    input.handle_mouse_wheel(*delta);
+                },
                 _ => ()
             }
             Event::RedrawRequested(window_id) if window_id == frug_instance.window.id() => {
-                // frug_instance.update();
+                let now = std::time::Instant::now();
+                let delta_time = (now - last_frame_time).as_secs_f32();
+                last_frame_time = now;
+
+                let mut context = FrugContext { instance: &mut frug_instance, input: &input };
+                window_loop(&mut context, delta_time);
+                input.clear_deltas();
+
                 match frug_instance.render() {
                     Ok(_) => {}
                     // Reconfigure the surface if lost
@@ -333,8 +605,6 @@ pub fn run<F: 'static + Fn()>(window_title: &str, window_loop: F) {
             }
             _ => (),
         }
-
-        window_loop();
     });
 }
 
@@ -0,0 +1,54 @@
+//! Text rendering via a glyph brush.
+
+use wgpu_glyph::{ab_glyph, GlyphBrush, GlyphBrushBuilder, Section, Text};
+
+/// Holds the glyph brush and staging belt used to draw queued text each frame.
+pub(crate) struct TextRenderer {
+    glyph_brush: GlyphBrush<()>,
+    staging_belt: wgpu::util::StagingBelt
+}
+
+impl TextRenderer {
+    /// Loads a TTF font from disk and builds a glyph brush targeting the given surface format.
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat, font_path: &str) -> Self {
+        let font_bytes = std::fs::read(font_path).expect("Failed to read font file.");
+        let font = ab_glyph::FontArc::try_from_vec(font_bytes).expect("Failed to parse font file.");
+        let glyph_brush = GlyphBrushBuilder::using_font(font).build(device, format);
+        let staging_belt = wgpu::util::StagingBelt::new(1024);
+
+        Self { glyph_brush, staging_belt }
+    }
+
+    /// Queues a section of text to be drawn on the next `draw_queued` call.
+    pub fn queue(&mut self, text: &str, position: (f32, f32), color: [f32; 4], scale: f32) {
+        self.glyph_brush.queue(Section {
+            screen_position: position,
+            text: vec![Text::new(text).with_color(color).with_scale(scale)],
+            ..Section::default()
+        });
+    }
+
+    /// Draws every section queued since the last call, against the given view.
+    pub fn draw_queued(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        view: &wgpu::TextureView,
+        width: u32,
+        height: u32
+    ) {
+        self.glyph_brush
+            .draw_queued(device, &mut self.staging_belt, encoder, view, width, height)
+            .expect("Failed to draw queued text.");
+    }
+
+    /// Finishes the staging belt's uploads. Must be called before submitting the encoder.
+    pub fn finish(&mut self) {
+        self.staging_belt.finish();
+    }
+
+    /// Recalls the staging belt's buffers for reuse. Must be called after presenting the frame.
+    pub fn recall(&mut self) {
+        self.staging_belt.recall();
+    }
+}